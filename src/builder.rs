@@ -4,8 +4,9 @@ for details.
 */
 
 use core::fmt::{self, Debug, Formatter};
-
-use arrayvec::ArrayVec;
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ptr;
+use core::slice;
 
 /**
 Error type returned from [`ArrayBuilder::try_push`], indicating that the
@@ -26,6 +27,21 @@ pub enum PushResult {
     Full,
 }
 
+/**
+Error type returned from [`ArrayBuilder::try_from_iter`], distinguishing an
+iterator that didn't yield enough items from one that yielded too many.
+*/
+#[derive(Debug, Clone)]
+pub enum TryFromIteratorError<T, const N: usize> {
+    /// The iterator was exhausted before `N` items were yielded. Contains
+    /// the partially initialized builder, so the caller can recover the
+    /// prefix that was already built, or keep pushing more items onto it.
+    TooShort(ArrayBuilder<T, N>),
+
+    /// The iterator yielded more than `N` items.
+    TooLong,
+}
+
 /**
 Low-level builder type for `[T; N]` arrays. Uses a
 [`push`][ArrayBuilder::push] + [`finish`][ArrayBuilder::finish] interface to
@@ -38,9 +54,11 @@ Consider instead the misuse-resistant
 ownership semantics to provide only infallible operations, or the
 [`build!`][crate::build] macro at the top level of the crate.
 */
-#[derive(Clone)]
 pub struct ArrayBuilder<T, const N: usize> {
-    vec: ArrayVec<T, N>,
+    data: [MaybeUninit<T>; N],
+
+    // Invariant: the first `initialized` elements of `data` are initialized.
+    initialized: usize,
 }
 
 impl<T, const N: usize> ArrayBuilder<T, N> {
@@ -49,7 +67,10 @@ impl<T, const N: usize> ArrayBuilder<T, N> {
     */
     pub const fn new() -> Self {
         Self {
-            vec: ArrayVec::new_const(),
+            // Safety: a `MaybeUninit<T>` doesn't require its contents to be
+            // initialized, so an array of them doesn't either.
+            data: unsafe { MaybeUninit::uninit().assume_init() },
+            initialized: 0,
         }
     }
 
@@ -59,7 +80,7 @@ impl<T, const N: usize> ArrayBuilder<T, N> {
     */
     #[inline]
     pub fn is_full(&self) -> bool {
-        self.vec.is_full()
+        self.initialized >= N
     }
 
     /**
@@ -67,7 +88,7 @@ impl<T, const N: usize> ArrayBuilder<T, N> {
     */
     #[inline]
     pub fn is_empty(&self) -> bool {
-        self.vec.is_empty()
+        self.initialized == 0
     }
 
     /**
@@ -75,7 +96,7 @@ impl<T, const N: usize> ArrayBuilder<T, N> {
     */
     #[inline]
     pub fn len(&self) -> usize {
-        self.vec.len()
+        self.initialized
     }
 
     /**
@@ -96,10 +117,12 @@ impl<T, const N: usize> ArrayBuilder<T, N> {
     /// This must only be called when the builder is not full.
     #[inline]
     pub unsafe fn push_unchecked(&mut self, value: T) -> PushResult {
-        debug_assert!(self.vec.len() < N);
+        debug_assert!(self.initialized < N);
 
-        // Safety: the caller has ensured that the array isn't full yet.
-        self.vec.push_unchecked(value);
+        // Safety: the caller has ensured that the array isn't full yet, so
+        // `initialized` is a valid index into `data`.
+        self.data.get_unchecked_mut(self.initialized).write(value);
+        self.initialized += 1;
         self.push_result()
     }
 
@@ -110,10 +133,12 @@ impl<T, const N: usize> ArrayBuilder<T, N> {
     */
     #[inline]
     pub fn try_push(&mut self, value: T) -> Result<PushResult, Overflow<T>> {
-        self.vec
-            .try_push(value)
-            .map(|()| self.push_result())
-            .map_err(|err| Overflow(err.element()))
+        if self.is_full() {
+            Err(Overflow(value))
+        } else {
+            // Safety: just checked that the array isn't full.
+            Ok(unsafe { self.push_unchecked(value) })
+        }
     }
 
     /**
@@ -141,8 +166,16 @@ impl<T, const N: usize> ArrayBuilder<T, N> {
     /// This must only be called when the builder is full.
     #[inline]
     pub unsafe fn finish_unchecked(self) -> [T; N] {
-        debug_assert!(self.vec.len() == N);
-        self.vec.into_inner_unchecked()
+        debug_assert!(self.initialized == N);
+
+        // Wrapping in `ManuallyDrop` suppresses `self`'s own `Drop` impl, so
+        // that reading the elements out of it below doesn't leave them to
+        // also be dropped in place when `self` goes out of scope.
+        let this = ManuallyDrop::new(self);
+
+        // Safety: the caller has ensured every element is initialized, and
+        // `[MaybeUninit<T>; N]` has the same layout as `[T; N]`.
+        ptr::read(&this.data as *const [MaybeUninit<T>; N] as *const [T; N])
     }
 
     /**
@@ -151,7 +184,12 @@ impl<T, const N: usize> ArrayBuilder<T, N> {
     */
     #[inline]
     pub fn try_finish(self) -> Result<[T; N], Self> {
-        self.vec.into_inner().map_err(|vec| Self { vec })
+        if self.is_full() {
+            // Safety: just checked that the array is full.
+            Ok(unsafe { self.finish_unchecked() })
+        } else {
+            Err(self)
+        }
     }
 
     /**
@@ -174,7 +212,9 @@ impl<T, const N: usize> ArrayBuilder<T, N> {
     */
     #[inline]
     pub fn finished_slice(&self) -> &[T] {
-        self.vec.as_slice()
+        // Safety: the first `initialized` elements of `data` are
+        // initialized.
+        unsafe { slice::from_raw_parts(self.data.as_ptr().cast::<T>(), self.initialized) }
     }
 
     /**
@@ -182,7 +222,89 @@ impl<T, const N: usize> ArrayBuilder<T, N> {
     */
     #[inline]
     pub fn finished_slice_mut(&mut self) -> &mut [T] {
-        self.vec.as_mut_slice()
+        // Safety: the first `initialized` elements of `data` are
+        // initialized.
+        unsafe { slice::from_raw_parts_mut(self.data.as_mut_ptr().cast::<T>(), self.initialized) }
+    }
+
+    /**
+    Build an array by pulling items from an iterator, distinguishing "too
+    few" items from "too many" items, rather than collapsing both cases
+    into `None`.
+
+    Returns [`TryFromIteratorError::TooShort`] with the partially built
+    builder if the iterator was exhausted before `N` items were yielded, or
+    [`TryFromIteratorError::TooLong`] if the iterator still had items left
+    after `N` were taken.
+    */
+    pub fn try_from_iter(
+        iter: impl IntoIterator<Item = T>,
+    ) -> Result<[T; N], TryFromIteratorError<T, N>> {
+        let mut iter = iter.into_iter();
+        let mut builder = Self::new();
+
+        for value in iter.by_ref().take(N) {
+            builder.push(value);
+        }
+
+        match builder.try_finish() {
+            Ok(array) => match iter.next() {
+                Some(_) => Err(TryFromIteratorError::TooLong),
+                None => Ok(array),
+            },
+            Err(builder) => Err(TryFromIteratorError::TooShort(builder)),
+        }
+    }
+
+    /**
+    Consume the builder, passing each already-initialized element to `f` in
+    order.
+
+    Unlike collecting the initialized prefix into an owning iterator, this
+    walks a pointer over the initialized elements and calls `f` directly,
+    which stays pointer-sized regardless of `N` and so optimizes better for
+    large arrays. If `f` panics partway through, the not-yet-visited
+    elements are still correctly dropped.
+    */
+    pub fn drain_with(self, f: impl FnMut(T)) {
+        // Suppress `self`'s own `Drop` impl: `drain_ptr_with`'s guard takes
+        // over responsibility for dropping the not-yet-visited elements.
+        let mut this = ManuallyDrop::new(self);
+
+        // Safety: the first `initialized` elements of `data` are
+        // initialized, and `this` is a `ManuallyDrop`, so they won't be
+        // dropped again once `drain_ptr_with` has consumed them.
+        unsafe { crate::drain_ptr_with(this.data.as_mut_ptr().cast::<T>(), this.initialized, f) }
+    }
+}
+
+impl<T, const N: usize> Drop for ArrayBuilder<T, N> {
+    fn drop(&mut self) {
+        // Safety: the first `initialized` elements of `data` are
+        // initialized, and we own them, so it's safe to drop them in place.
+        // This runs even if a user's push expression panics mid-build, so
+        // the already-placed elements are always destructed exactly once.
+        unsafe {
+            let initialized =
+                ptr::slice_from_raw_parts_mut(self.data.as_mut_ptr().cast::<T>(), self.initialized);
+
+            ptr::drop_in_place(initialized);
+        }
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for ArrayBuilder<T, N> {
+    fn clone(&self) -> Self {
+        let mut builder = Self::new();
+
+        for item in self.finished_slice() {
+            // Safety: `builder` is a fresh `ArrayBuilder` of the same
+            // capacity, and we push at most `self.initialized <= N` items
+            // into it.
+            unsafe { builder.push_unchecked(item.clone()) };
+        }
+
+        builder
     }
 }
 
@@ -210,3 +332,104 @@ impl<T, const N: usize> Extend<T> for ArrayBuilder<T, N> {
 
     // TODO: extend_one, when it's stable
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+
+    #[test]
+    fn push_and_finish() {
+        let mut builder = ArrayBuilder::<i32, 3>::new();
+        assert_eq!(builder.push(1), PushResult::NotFull);
+        assert_eq!(builder.push(2), PushResult::NotFull);
+        assert_eq!(builder.push(3), PushResult::Full);
+        assert_eq!(builder.finish(), [1, 2, 3]);
+    }
+
+    #[test]
+    fn try_push_overflow() {
+        let mut builder = ArrayBuilder::<i32, 1>::new();
+        assert!(matches!(builder.try_push(1), Ok(PushResult::Full)));
+
+        match builder.try_push(2) {
+            Err(Overflow(2)) => {}
+            other => panic!("expected Err(Overflow(2)), got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn try_finish_incomplete_returns_builder() {
+        let mut builder = ArrayBuilder::<i32, 2>::new();
+        builder.push(1);
+
+        let builder = builder.try_finish().unwrap_err();
+        assert_eq!(builder.finished_slice(), [1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "ArrayBuilder::finish incomplete")]
+    fn finish_incomplete_panics() {
+        let mut builder = ArrayBuilder::<i32, 2>::new();
+        builder.push(1);
+        builder.finish();
+    }
+
+    /// A type that records in a shared counter every time it's dropped, so
+    /// that tests can assert exactly how many elements were destructed.
+    struct DropCounter<'a>(&'a Cell<usize>);
+
+    impl Drop for DropCounter<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    #[test]
+    fn try_from_iter_exact() {
+        match ArrayBuilder::<i32, 3>::try_from_iter([1, 2, 3]) {
+            Ok(array) => assert_eq!(array, [1, 2, 3]),
+            Err(err) => panic!("expected Ok([1, 2, 3]), got Err({err:?})"),
+        }
+    }
+
+    #[test]
+    fn try_from_iter_too_short() {
+        match ArrayBuilder::<i32, 4>::try_from_iter([1, 2]) {
+            Err(TryFromIteratorError::TooShort(builder)) => {
+                assert_eq!(builder.finished_slice(), [1, 2]);
+            }
+            other => panic!("expected Err(TooShort(..)), got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn try_from_iter_too_long() {
+        assert!(matches!(
+            ArrayBuilder::<i32, 2>::try_from_iter([1, 2, 3]),
+            Err(TryFromIteratorError::TooLong)
+        ));
+    }
+
+    #[test]
+    fn push_panic_drops_initialized_prefix_exactly_once() {
+        extern crate std;
+        use std::panic::{self, AssertUnwindSafe};
+
+        let drops = Cell::new(0);
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            let mut builder = ArrayBuilder::<DropCounter<'_>, 4>::new();
+            builder.push(DropCounter(&drops));
+            builder.push(DropCounter(&drops));
+
+            // Simulates a push expression panicking mid-build; `builder`
+            // unwinds out of scope with only 2 of its 4 elements
+            // initialized.
+            panic!("simulated panic partway through building the array");
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(drops.get(), 2);
+    }
+}
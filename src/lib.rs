@@ -125,6 +125,137 @@ macro_rules! build {
     };
 }
 
+/**
+Build an array by calling `f` once for each index in `0..N`, in order.
+
+This is a function equivalent of the [`build!`] macro, for contexts where a
+plain callable is needed (for instance, passing it as a generic argument)
+rather than an inline expression.
+
+```rust
+use brownstone::from_fn;
+
+let array: [i32; 5] = from_fn(|i| i as i32 * 2);
+assert_eq!(array, [0, 2, 4, 6, 8]);
+```
+*/
+pub fn from_fn<T, const N: usize>(mut f: impl FnMut(usize) -> T) -> [T; N] {
+    let mut builder = builder::ArrayBuilder::new();
+
+    while !builder.is_full() {
+        builder.push(f(builder.len()));
+    }
+
+    builder.finish()
+}
+
+/**
+Build an array by calling `f` once for each index in `0..N`, in order,
+short-circuiting on the first error.
+
+This is a fallible function equivalent of the [`build!`] macro; unlike the
+macro's own short-circuiting support (via `?` or similar control flow),
+`try_from_fn` is a plain callable usable in generic contexts.
+
+```rust
+use brownstone::try_from_fn;
+
+let array: Result<[i32; 4], &str> = try_from_fn(|i| if i < 3 { Ok(i as i32) } else { Err("too big") });
+assert_eq!(array, Err("too big"));
+```
+*/
+pub fn try_from_fn<T, E, const N: usize>(
+    mut f: impl FnMut(usize) -> Result<T, E>,
+) -> Result<[T; N], E> {
+    let mut builder = builder::ArrayBuilder::new();
+
+    while !builder.is_full() {
+        let value = f(builder.len())?;
+        builder.push(value);
+    }
+
+    Ok(builder.finish())
+}
+
+/// Pass each of the `len` initialized elements starting at `ptr` to `f`, in
+/// order, by value.
+///
+/// Walking a pointer over the elements like this (rather than, say,
+/// collecting them into an owning iterator first) stays pointer-sized
+/// regardless of how many elements there are, so it optimizes better for
+/// large arrays. If `f` panics partway through, the not-yet-visited
+/// elements are still correctly dropped; shared with
+/// [`builder::ArrayBuilder::drain_with`] so the drop-on-panic guard only
+/// has to be gotten right once.
+///
+/// # Safety
+///
+/// `ptr` must be valid for reads and writes of `len` consecutive,
+/// initialized `T`s, and the caller must not use those elements again after
+/// calling this function (whether or not `f` panics).
+unsafe fn drain_ptr_with<T>(ptr: *mut T, len: usize, mut f: impl FnMut(T)) {
+    // Drops the elements from `ptr` to `ptr + remaining` in place. Kept up
+    // to date on every iteration below, so a panic from `f` still leaves
+    // the remaining elements correctly destructed.
+    struct Guard<T> {
+        ptr: *mut T,
+        remaining: usize,
+    }
+
+    impl<T> Drop for Guard<T> {
+        fn drop(&mut self) {
+            // Safety: `remaining` elements starting at `ptr` are
+            // initialized and owned by this guard.
+            unsafe {
+                core::ptr::drop_in_place(core::ptr::slice_from_raw_parts_mut(
+                    self.ptr,
+                    self.remaining,
+                ));
+            }
+        }
+    }
+
+    let mut guard = Guard { ptr, remaining: len };
+
+    while guard.remaining > 0 {
+        // Safety: the front element is initialized and owned by the guard;
+        // reading it out and shrinking `remaining` before calling `f` keeps
+        // the guard's invariant intact even if `f` panics.
+        let value = unsafe { core::ptr::read(guard.ptr) };
+        guard.ptr = unsafe { guard.ptr.add(1) };
+        guard.remaining -= 1;
+
+        f(value);
+    }
+}
+
+/**
+Consume an array, passing each element to `f` in order.
+
+Moving an entire `[T; N]` into an owning `array::IntoIter` pessimizes
+codegen for large `N`, since the iterator struct itself is array-sized.
+`drain_with` instead walks a pointer over the array and calls `f` once per
+element, which stays pointer-sized and optimizes better. If `f` panics
+partway through, the not-yet-visited elements are still correctly dropped.
+
+```rust
+use brownstone::drain_with;
+
+let mut sum = 0;
+drain_with([1, 2, 3, 4], |item| sum += item);
+assert_eq!(sum, 10);
+```
+*/
+pub fn drain_with<T, const N: usize>(array: [T; N], f: impl FnMut(T)) {
+    // Suppress the array's own drop glue: `drain_ptr_with`'s guard takes
+    // over responsibility for dropping the not-yet-visited elements.
+    let mut array = core::mem::ManuallyDrop::new(array);
+
+    // Safety: `array` is a `ManuallyDrop`, so these `N` elements won't be
+    // dropped again once `drain_ptr_with` has consumed them.
+    unsafe { drain_ptr_with(array.as_mut_ptr(), N, f) }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -177,4 +308,37 @@ mod tests {
 
         assert_eq!(array, ["hello", "hello", "hello", "hello", "hello"]);
     }
+
+    #[test]
+    fn from_fn_basic() {
+        let array: [i32; 5] = from_fn(|i| i as i32);
+        assert_eq!(array, [0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn try_from_fn_ok() {
+        let array: Result<[i32; 4], &str> = try_from_fn(|i| Ok(i as i32));
+        assert_eq!(array, Ok([0, 1, 2, 3]));
+    }
+
+    #[test]
+    fn try_from_fn_err() {
+        let array: Result<[i32; 4], &str> =
+            try_from_fn(|i| if i < 2 { Ok(i as i32) } else { Err("too big") });
+        assert_eq!(array, Err("too big"));
+    }
+
+    #[test]
+    fn drain_with_visits_in_order() {
+        let mut sum = 0;
+        let mut count = 0;
+
+        drain_with([1, 2, 3, 4], |item| {
+            sum += item;
+            count += 1;
+        });
+
+        assert_eq!(sum, 10);
+        assert_eq!(count, 4);
+    }
 }
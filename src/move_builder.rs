@@ -77,4 +77,93 @@ impl<T, const N: usize> ArrayBuilder<T, N> {
     pub fn finished_slice_mut(&mut self) -> &mut [T] {
         self.builder.finished_slice_mut()
     }
+
+    /// Consume the builder, passing each already-initialized element to `f`
+    /// in order.
+    ///
+    /// This is more efficient than collecting the initialized prefix into an
+    /// owning iterator first, since it walks a pointer over the elements
+    /// directly rather than materializing an iterator struct sized for the
+    /// whole array.
+    #[inline]
+    pub fn drain_with(self, f: impl FnMut(T)) {
+        self.builder.drain_with(f);
+    }
+
+    /// Build an array by pulling exactly `N` items from the front of an
+    /// iterator.
+    ///
+    /// Returns `None` if the iterator is exhausted before yielding `N`
+    /// items; the elements already pulled from the iterator are dropped.
+    #[inline]
+    pub fn collect_from(iter: impl IntoIterator<Item = T>) -> Option<[T; N]> {
+        next_array(&mut iter.into_iter())
+    }
+}
+
+/// Pull the next `N` items from an iterator into an array, leaving the rest
+/// of the iterator untouched for continued iteration.
+///
+/// Returns `None`, after consuming the rest of the iterator, if fewer than
+/// `N` items were available. This is useful for splitting a stream into
+/// fixed-size windows.
+pub fn next_array<T, const N: usize>(iter: &mut impl Iterator<Item = T>) -> Option<[T; N]> {
+    let mut builder = ArrayBuilder::start();
+
+    loop {
+        builder = match builder {
+            PushResult::Full(array) => break Some(array),
+            PushResult::NotFull(builder) => match iter.next() {
+                Some(item) => builder.push(item),
+                None => break None,
+            },
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collect_from_exact() {
+        let array: Option<[i32; 4]> = ArrayBuilder::collect_from([1, 2, 3, 4]);
+        assert_eq!(array, Some([1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn collect_from_too_few() {
+        let array: Option<[i32; 4]> = ArrayBuilder::collect_from([1, 2, 3]);
+        assert_eq!(array, None);
+    }
+
+    #[test]
+    fn next_array_leaves_remainder() {
+        let mut iter = [1, 2, 3, 4, 5].into_iter();
+
+        let chunk: Option<[i32; 2]> = next_array(&mut iter);
+        assert_eq!(chunk, Some([1, 2]));
+
+        assert!(iter.eq([3, 4, 5]));
+    }
+
+    #[test]
+    fn drain_with_partial_builder() {
+        let PushResult::NotFull(builder) = ArrayBuilder::<i32, 4>::start() else {
+            unreachable!("N != 0, so the builder can't start full");
+        };
+
+        let PushResult::NotFull(builder) = builder.push(1) else {
+            unreachable!("builder isn't full after 1 of 4 pushes");
+        };
+
+        let PushResult::NotFull(builder) = builder.push(2) else {
+            unreachable!("builder isn't full after 2 of 4 pushes");
+        };
+
+        let mut sum = 0;
+        builder.drain_with(|item| sum += item);
+
+        assert_eq!(sum, 3);
+    }
 }